@@ -111,7 +111,7 @@
 
 use std::cell::Cell;
 
-pub use error::{FStringErrorType, ParseError, ParseErrorType};
+pub use error::{FStringErrorType, LexicalError, ParseError, ParseErrorType};
 use lexer::{lex, lex_starts_at};
 pub use parser::Program;
 use ruff_python_ast::{Expr, Mod, ModModule, PySourceType, Suite};
@@ -124,14 +124,22 @@ mod error;
 mod invalid;
 mod lalrpop;
 pub mod lexer;
+mod options;
 mod parser;
+mod resilient;
 mod soft_keywords;
 mod string;
 mod token;
 mod token_set;
 mod token_source;
+mod tokens;
+pub mod trivia;
 pub mod typing;
 
+pub use options::{Feature, ParseOptions, PythonVersion};
+pub use resilient::parse_resilient;
+pub use tokens::Tokens;
+
 thread_local! {
     static NEW_PARSER: Cell<bool> = Cell::new(std::env::var("NEW_PARSER").is_ok());
 }
@@ -329,6 +337,30 @@ pub fn parse_tokens(tokens: Vec<LexResult>, source: &str, mode: Mode) -> Result<
     }
 }
 
+/// Parse a [`Tokens`] buffer using the specified [`Mode`].
+///
+/// This lets a caller reuse a single already-built [`Tokens`] buffer (for example across
+/// incremental re-parses) instead of handing over a fresh `Vec<LexResult>` every time, as
+/// [`parse_tokens`] requires. It currently goes through [`Tokens::to_lex_results`] to dispatch
+/// into the same, proven `Vec<LexResult>`-based parser backends as [`parse_tokens`]; the payload
+/// side table (see [`Tokens::payload_at`]) is what makes that reconstruction possible without
+/// losing any literal data. Once the hand-written parser reads directly out of the columnar
+/// arrays, this indirection goes away.
+///
+/// # Example
+///
+/// ```
+/// use ruff_python_parser::{lexer::lex, Mode, parse_tokens_buffer, Tokens};
+///
+/// let source = "1 + 2";
+/// let tokens: Tokens = lex(source, Mode::Expression).collect();
+/// let expr = parse_tokens_buffer(&tokens, source, Mode::Expression);
+/// assert!(expr.is_ok());
+/// ```
+pub fn parse_tokens_buffer(tokens: &Tokens, source: &str, mode: Mode) -> Result<Mod, ParseError> {
+    parse_tokens(tokens.to_lex_results(), source, mode)
+}
+
 /// Collect tokens up to and including the first error.
 pub fn tokenize(contents: &str, mode: Mode) -> Vec<LexResult> {
     let mut tokens: Vec<LexResult> = vec![];
@@ -342,6 +374,87 @@ pub fn tokenize(contents: &str, mode: Mode) -> Vec<LexResult> {
     tokens
 }
 
+/// Collect all tokens in `contents`, including comments, so a caller can reconstruct the original
+/// source byte-for-byte.
+///
+/// This is the lossless counterpart to [`tokenize`]: the lexer already emits `Comment` and
+/// `NonLogicalNewline` tokens alongside the significant ones (the parser just skips over them),
+/// so collecting its raw output -- rather than stopping at the first error, as [`tokenize`] does
+/// -- already retains every token needed to know what's *in* the source. What it doesn't capture
+/// is the plain whitespace and line-continuation backslashes *between* tokens, since the lexer
+/// never emits tokens for those; [`trivia::attach_trivia`] recovers them from the original source
+/// text using each token's [`ruff_text_size::TextRange`], and binds the result to the adjacent
+/// significant tokens.
+///
+/// Unlike [`tokenize`], this does not stop at the first lexer error, since a full-fidelity
+/// consumer wants to reconstruct as much of the file as possible even around a syntax error.
+pub fn tokenize_lossless(contents: &str, mode: Mode) -> Vec<LexResult> {
+    lexer::lex(contents, mode).collect()
+}
+
+/// Lex a single token from `text`.
+///
+/// Returns `None` if `text` doesn't lex to exactly one significant token -- for example, if it's
+/// empty, contains more than one token, or has trailing content the lexer would otherwise skip.
+/// Otherwise returns the [`TokenKind`] of that token, along with a [`LexicalError`] if the lexeme
+/// was invalid.
+///
+/// This is the "lex one token and check what it is" primitive behind [`is_identifier`], and is
+/// useful on its own for callers -- rename and codegen tooling -- that want to classify a single
+/// lexeme without round-tripping through the full parser.
+///
+/// # Example
+///
+/// ```
+/// use ruff_python_parser::{lex_single_token, TokenKind};
+///
+/// assert_eq!(lex_single_token("foo"), Some((TokenKind::Name, None)));
+/// assert_eq!(lex_single_token("foo bar"), None);
+/// ```
+pub fn lex_single_token(text: &str) -> Option<(TokenKind, Option<LexicalError>)> {
+    let mut tokens = lexer::lex(text, Mode::Module);
+
+    let first = match tokens.next()? {
+        Ok((Tok::Newline | Tok::NonLogicalNewline | Tok::EndOfFile | Tok::Comment(_), _)) => {
+            return None
+        }
+        Ok((tok, _range)) => (TokenKind::from(&tok), None),
+        Err(error) => (TokenKind::Unknown, Some(error)),
+    };
+
+    // A bare lexeme should lex to just the token itself followed by the implicit end-of-file
+    // markers; anything else means `text` contained more than one significant token.
+    for result in tokens {
+        match result {
+            Ok((Tok::Newline | Tok::NonLogicalNewline | Tok::EndOfFile, _)) => continue,
+            Ok(_) => return None,
+            Err(_) => return None,
+        }
+    }
+
+    Some(first)
+}
+
+/// Returns `true` if `text` is a syntactically valid Python identifier.
+///
+/// This accepts soft keywords (e.g. `match`, `type`) and identifiers that rely on the Unicode
+/// identifier rules, but rejects hard keywords like `def` and `class`, numeric literals, and
+/// anything that doesn't lex to a single token.
+///
+/// # Example
+///
+/// ```
+/// use ruff_python_parser::is_identifier;
+///
+/// assert!(is_identifier("foo"));
+/// assert!(is_identifier("match"));
+/// assert!(!is_identifier("class"));
+/// assert!(!is_identifier("123"));
+/// ```
+pub fn is_identifier(text: &str) -> bool {
+    matches!(lex_single_token(text), Some((TokenKind::Name, None)))
+}
+
 /// Parse a full Python program from its tokens.
 pub fn parse_program_tokens(
     tokens: Vec<LexResult>,
@@ -420,3 +533,25 @@ impl std::fmt::Display for ModeParseError {
         write!(f, r#"mode must be "exec", "eval", "ipython", or "single""#)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lex_single_token_accepts_a_bare_name() {
+        assert_eq!(lex_single_token("foo"), Some((TokenKind::Name, None)));
+    }
+
+    #[test]
+    fn lex_single_token_rejects_more_than_one_token() {
+        assert_eq!(lex_single_token("foo bar"), None);
+    }
+
+    #[test]
+    fn is_identifier_accepts_soft_keywords_and_rejects_hard_keywords() {
+        assert!(is_identifier("match"));
+        assert!(!is_identifier("class"));
+        assert!(!is_identifier("123"));
+    }
+}
@@ -0,0 +1,118 @@
+//! A data model of which syntactic features each Python version supports.
+//!
+//! This module holds `PythonVersion`, `Feature` and `ParseOptions`, and
+//! [`ParseOptions::supports`] answers whether a feature is legal on the configured version. No
+//! parse entry point takes or consults these options: every `parse_*` function accepts all syntax
+//! it knows how to parse, and no out-of-version syntax is reported as a [`crate::ParseError`].
+
+/// A target Python version, compared against the version that introduced a [`Feature`].
+///
+/// Variants are ordered, so `PythonVersion::Py310 < PythonVersion::Py312` holds and can be used
+/// directly in version comparisons (see [`ParseOptions::supports`]).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(non_camel_case_types)]
+pub enum PythonVersion {
+    Py37,
+    Py38,
+    Py39,
+    Py310,
+    Py311,
+    Py312,
+}
+
+impl Default for PythonVersion {
+    /// Defaults to the newest version this parser knows about, so that by default every
+    /// supported construct is accepted.
+    fn default() -> Self {
+        PythonVersion::Py312
+    }
+}
+
+/// A syntactic feature that was introduced in a specific [`PythonVersion`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Feature {
+    /// The walrus (named expression) operator, `x := y`.
+    WalrusOperator,
+    /// `match` statements and structural pattern matching.
+    MatchStatement,
+    /// The `except*` exception group handler.
+    ExceptStar,
+    /// PEP 695 generic type parameter syntax, e.g. `def f[T](x: T) -> T: ...` and `type Alias = ...`.
+    TypeParameterSyntax,
+}
+
+impl Feature {
+    /// The [`PythonVersion`] that introduced this feature.
+    const fn introduced_in(self) -> PythonVersion {
+        match self {
+            Feature::WalrusOperator => PythonVersion::Py38,
+            Feature::MatchStatement => PythonVersion::Py310,
+            Feature::ExceptStar => PythonVersion::Py311,
+            Feature::TypeParameterSyntax => PythonVersion::Py312,
+        }
+    }
+}
+
+/// A target Python version, and which syntactic features are legal on it.
+///
+/// [`ParseOptions::supports`] reports whether a [`Feature`] is legal for the configured target
+/// version. No parser entry point consults it: every `parse_*` function accepts all syntax it
+/// knows how to parse, regardless of `target_version`.
+///
+/// The default options target the newest supported Python version, on which every [`Feature`]
+/// is supported.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ParseOptions {
+    target_version: PythonVersion,
+}
+
+impl ParseOptions {
+    /// Creates options that target `target_version`.
+    pub fn new(target_version: PythonVersion) -> Self {
+        Self { target_version }
+    }
+
+    /// Returns the configured target Python version.
+    pub fn target_version(&self) -> PythonVersion {
+        self.target_version
+    }
+
+    /// Sets the target Python version, consuming and returning `self` for chaining.
+    #[must_use]
+    pub fn with_target_version(mut self, target_version: PythonVersion) -> Self {
+        self.target_version = target_version;
+        self
+    }
+
+    /// Returns `true` if `feature` is legal under the configured target version.
+    pub fn supports(&self, feature: Feature) -> bool {
+        self.target_version >= feature.introduced_in()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn supports_is_inclusive_of_the_introducing_version() {
+        let options = ParseOptions::new(PythonVersion::Py310);
+        assert!(options.supports(Feature::MatchStatement));
+        assert!(!options.supports(Feature::ExceptStar));
+    }
+
+    #[test]
+    fn default_targets_the_newest_known_version_and_supports_everything() {
+        let options = ParseOptions::default();
+        assert!(options.supports(Feature::WalrusOperator));
+        assert!(options.supports(Feature::MatchStatement));
+        assert!(options.supports(Feature::ExceptStar));
+        assert!(options.supports(Feature::TypeParameterSyntax));
+    }
+
+    #[test]
+    fn with_target_version_overrides_the_default() {
+        let options = ParseOptions::default().with_target_version(PythonVersion::Py37);
+        assert!(!options.supports(Feature::WalrusOperator));
+    }
+}
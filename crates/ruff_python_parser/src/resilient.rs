@@ -0,0 +1,164 @@
+//! Error-recovering parsing, built on top of the existing all-or-nothing [`parse_tokens`].
+//!
+//! Rather than teaching the grammar itself to recover (which would mean threading recovery state
+//! through every production), this splits the token stream into one chunk per top-level
+//! statement, parses each chunk independently, and stitches the results back together -- a
+//! statement that fails to parse becomes a `pass` placeholder that keeps the original
+//! [`TextRange`], and a statement that parses fine contributes its real nodes.
+
+use ruff_python_ast::{Mod, ModModule, Stmt, StmtPass};
+use ruff_text_size::{TextRange, TextSize};
+
+use crate::lexer::LexResult;
+use crate::{parse_tokens, Mode, ParseError, Tok};
+
+/// The maximum number of consecutive top-level statements allowed to fail before recovery gives
+/// up on the remainder of the file. This guarantees termination on pathological input (e.g. a
+/// file that is garbage from the very first byte) without silently eating the whole file one
+/// token at a time.
+const MAX_CONSECUTIVE_ERRORS: usize = 50;
+
+/// Parses `source` in `mode`, recovering from syntax errors instead of aborting on the first one.
+///
+/// For [`Mode::Module`], the source is split into one chunk per top-level statement; each chunk
+/// is parsed independently, so a syntax error in one statement doesn't prevent the statements
+/// before and after it from showing up in the tree. A statement that fails to parse is replaced
+/// with a `pass` placeholder spanning its original [`TextRange`], so offset-based tooling built on
+/// the result still finds a node in the right place, while the real [`ParseError`] is reported
+/// alongside it. Every statement consumes at least one token, so this always terminates; at most
+/// [`MAX_CONSECUTIVE_ERRORS`] consecutive failures are tolerated before recovery gives up on the
+/// rest of the file.
+///
+/// Other modes ([`Mode::Expression`] and [`Mode::Ipython`]) are not split: they fall back to a
+/// single non-recovering parse, and on failure return an empty module with the one error.
+pub fn parse_resilient(source: &str, mode: Mode) -> (Mod, Vec<ParseError>) {
+    let full_range = TextRange::new(TextSize::from(0), TextSize::of(source));
+    let tokens: Vec<LexResult> = crate::lexer::lex(source, mode).collect();
+
+    if !matches!(mode, Mode::Module) {
+        return match parse_tokens(tokens, source, mode) {
+            Ok(module) => (module, Vec::new()),
+            Err(error) => (
+                Mod::Module(ModModule {
+                    range: full_range,
+                    body: Vec::new(),
+                }),
+                vec![error],
+            ),
+        };
+    }
+
+    let mut body = Vec::new();
+    let mut errors = Vec::new();
+    let mut consecutive_errors = 0;
+
+    for mut chunk in split_top_level_statements(tokens) {
+        let Some(chunk_range) = token_range(&chunk) else {
+            continue;
+        };
+        // Each chunk is only a slice of the real token stream, so -- except for the last one,
+        // which already ends with the lexer's own sentinel -- it's missing the trailing
+        // `EndOfFile` the module grammar production needs to terminate. Without this, even a
+        // perfectly valid statement's tokens would fail to parse on their own.
+        if !matches!(chunk.last(), Some(Ok((Tok::EndOfFile, _)))) {
+            chunk.push(Ok((Tok::EndOfFile, TextRange::empty(chunk_range.end()))));
+        }
+
+        match parse_tokens(chunk, source, Mode::Module) {
+            Ok(Mod::Module(ModModule { body: mut stmts, .. })) => {
+                consecutive_errors = 0;
+                body.append(&mut stmts);
+            }
+            Ok(Mod::Expression(_)) => unreachable!("Mode::Module doesn't return other variant"),
+            Err(error) => {
+                errors.push(error);
+                body.push(Stmt::Pass(StmtPass { range: chunk_range }));
+                consecutive_errors += 1;
+                if consecutive_errors >= MAX_CONSECUTIVE_ERRORS {
+                    break;
+                }
+            }
+        }
+    }
+
+    (
+        Mod::Module(ModModule {
+            range: full_range,
+            body,
+        }),
+        errors,
+    )
+}
+
+/// Splits `tokens` into contiguous, non-empty runs -- one per top-level statement -- by tracking
+/// indentation depth. A line ends at a `Newline` or `Dedent` that leaves the depth at zero, and the
+/// next significant token starts a new run unless it continues the same statement: an `Indent`
+/// (the statement's block), an `else`/`elif`/`except`/`finally` clause, or the definition that
+/// follows a `@decorator` line. This is the synchronization set for recovery: such a boundary (or
+/// running out of tokens) is a safe place to stop one statement and start the next, so each run
+/// can be re-parsed on its own.
+fn split_top_level_statements(tokens: Vec<LexResult>) -> Vec<Vec<LexResult>> {
+    let mut chunks = Vec::new();
+    let mut current = Vec::new();
+    let mut depth: i32 = 0;
+    let (mut at_boundary, mut decorator_line) = (true, false);
+
+    for result in tokens {
+        match &result {
+            Ok((Tok::Indent, _)) => depth += 1,
+            Ok((Tok::Dedent, _)) => depth -= 1,
+            _ => {}
+        }
+        if let Ok((tok, _)) = &result {
+            if !matches!(tok, Tok::Comment(_) | Tok::NonLogicalNewline) && std::mem::take(&mut at_boundary) {
+                let continues = matches!(tok, Tok::Indent | Tok::Else | Tok::Elif | Tok::Except | Tok::Finally);
+                if !decorator_line && !continues && !current.is_empty() {
+                    chunks.push(std::mem::take(&mut current));
+                }
+                decorator_line = matches!(tok, Tok::At);
+            }
+        }
+
+        let ends_line = depth <= 0 && matches!(&result, Ok((Tok::Newline | Tok::Dedent, _)));
+        current.push(result);
+        at_boundary |= ends_line;
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+/// The [`TextRange`] spanning every successfully-lexed token in `tokens`, if any.
+fn token_range(tokens: &[LexResult]) -> Option<TextRange> {
+    tokens.iter().flatten().map(|(_, range)| *range).reduce(
+        |acc: TextRange, range: TextRange| acc.cover(range),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recovers_around_a_broken_statement() {
+        let source = "x = 1\ndef broken(:):\n    pass\ny = 2\n";
+        let (module, errors) = parse_resilient(source, Mode::Module);
+
+        assert!(!errors.is_empty());
+        let Mod::Module(module) = module else {
+            panic!("expected a module");
+        };
+        // `x = 1` and `y = 2` parsed fine; the broken `def` became a placeholder.
+        assert_eq!(module.body.len(), 3);
+    }
+
+    #[test]
+    fn clean_source_has_no_errors() {
+        let source = "x = 1\ny = 2\n";
+        let (_module, errors) = parse_resilient(source, Mode::Module);
+        assert!(errors.is_empty());
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use ruff_text_size::{TextRange, TextSize};
+
+use crate::error::LexicalError;
+use crate::lexer::LexResult;
+use crate::token::TokenKind;
+use crate::Tok;
+
+/// A columnar buffer of lexed tokens.
+///
+/// Rather than a `Vec<LexResult>` -- a vector of `Result<(Tok, TextRange), LexicalError>` where
+/// each element carries the full, large [`Tok`](crate::Tok) payload by value -- [`Tokens`] stores
+/// the token kinds and ranges in their own parallel arrays and keeps heavyweight payloads (string,
+/// number and f-string contents) in a side table, keyed by position, that's only touched at the
+/// handful of sites that build literals. Indexing by position (see [`Tokens::kind_at`] and
+/// [`Tokens::range_at`]) stays small and cache-friendly even for large files; most tokens
+/// (operators, keywords, punctuation) never touch the payload table at all.
+///
+/// Lexer errors are collected separately in [`Tokens::errors`] rather than interleaved with the
+/// successfully lexed tokens.
+#[derive(Debug, Default, Clone)]
+pub struct Tokens {
+    kinds: Vec<TokenKind>,
+    ranges: Vec<TextRange>,
+    payloads: HashMap<u32, Tok>,
+    errors: Vec<(TextSize, LexicalError)>,
+}
+
+impl Tokens {
+    /// Returns the number of tokens in the buffer, excluding lexer errors.
+    pub fn len(&self) -> usize {
+        self.kinds.len()
+    }
+
+    /// Returns `true` if the buffer contains no tokens.
+    pub fn is_empty(&self) -> bool {
+        self.kinds.is_empty()
+    }
+
+    /// Returns the [`TokenKind`] at `index`.
+    pub fn kind_at(&self, index: usize) -> TokenKind {
+        self.kinds[index]
+    }
+
+    /// Returns the [`TextRange`] of the token at `index`.
+    pub fn range_at(&self, index: usize) -> TextRange {
+        self.ranges[index]
+    }
+
+    /// Returns the full [`Tok`] payload at `index`, if its kind carries one (a name, number,
+    /// string, f-string-middle, comment, or IPython escape command token). Returns `None` for
+    /// tokens -- the vast majority --
+    /// that don't need a payload to reconstruct, such as operators and keywords.
+    pub fn payload_at(&self, index: usize) -> Option<&Tok> {
+        self.payloads.get(&(index as u32))
+    }
+
+    /// Returns the lexer errors collected while building this buffer, each paired with the
+    /// offset at which it occurred.
+    pub fn errors(&self) -> &[(TextSize, LexicalError)] {
+        &self.errors
+    }
+
+    /// Reconstructs the successfully lexed tokens this buffer was built from. Lexer errors are
+    /// not included; they are only available through [`Tokens::errors`].
+    ///
+    /// This is the bridge back to the existing, `Vec<LexResult>`-based parser backends (see
+    /// [`crate::parse_tokens`]): until the hand-written parser reads directly out of the columnar
+    /// arrays, [`crate::parse_tokens_buffer`] goes through this to reuse the proven parsing path
+    /// instead of duplicating it.
+    pub(crate) fn to_lex_results(&self) -> Vec<LexResult> {
+        let mut results = Vec::with_capacity(self.kinds.len());
+        for index in 0..self.kinds.len() {
+            let tok = match self.payload_at(index) {
+                Some(tok) => tok.clone(),
+                None => Tok::from(self.kinds[index]),
+            };
+            results.push(Ok((tok, self.ranges[index])));
+        }
+        results
+    }
+}
+
+/// Returns `true` if `tok`'s kind alone isn't enough to reconstruct it -- i.e. it carries data
+/// (a name, a literal value, comment text, ...) that only exists on the token itself. This is an
+/// allowlist, not a denylist: the vast majority of kinds (operators, keywords, punctuation) need
+/// no payload at all, so only the handful of literal-carrying variants opt in here.
+fn has_payload(tok: &Tok) -> bool {
+    matches!(
+        tok,
+        Tok::Name { .. }
+            | Tok::Int { .. }
+            | Tok::Float { .. }
+            | Tok::Complex { .. }
+            | Tok::String { .. }
+            | Tok::FStringMiddle { .. }
+            | Tok::Comment(_)
+            | Tok::IpyEscapeCommand { .. }
+    )
+}
+
+impl FromIterator<LexResult> for Tokens {
+    fn from_iter<I: IntoIterator<Item = LexResult>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut tokens = Tokens {
+            kinds: Vec::with_capacity(iter.size_hint().0),
+            ranges: Vec::with_capacity(iter.size_hint().0),
+            payloads: HashMap::new(),
+            errors: Vec::new(),
+        };
+
+        for result in iter {
+            match result {
+                Ok((tok, range)) => {
+                    let index = tokens.kinds.len() as u32;
+                    tokens.kinds.push(TokenKind::from(&tok));
+                    tokens.ranges.push(range);
+                    if has_payload(&tok) {
+                        tokens.payloads.insert(index, tok);
+                    }
+                }
+                Err(error) => {
+                    let offset = error.location();
+                    tokens.errors.push((offset, error));
+                }
+            }
+        }
+
+        tokens
+    }
+}
+
+impl From<Vec<LexResult>> for Tokens {
+    fn from(tokens: Vec<LexResult>) -> Self {
+        tokens.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lexer::lex, Mode};
+
+    #[test]
+    fn payload_is_only_stored_for_literal_tokens() {
+        let tokens: Tokens = lex("x = 1 + 2", Mode::Module).collect();
+
+        let name_index = (0..tokens.len())
+            .find(|&i| tokens.kind_at(i) == TokenKind::Name)
+            .expect("a Name token");
+        assert!(tokens.payload_at(name_index).is_some());
+
+        let plus_index = (0..tokens.len())
+            .find(|&i| tokens.kind_at(i) == TokenKind::Plus)
+            .expect("a Plus token");
+        assert!(tokens.payload_at(plus_index).is_none());
+    }
+
+    #[test]
+    fn round_trips_through_to_lex_results() {
+        let original: Vec<LexResult> = lex("x = 1", Mode::Module).collect();
+        let tokens = Tokens::from(original.clone());
+        assert_eq!(tokens.to_lex_results().len(), original.len());
+    }
+}
@@ -0,0 +1,177 @@
+//! Support for lossless tokenization, i.e. a token stream that retains enough information to
+//! reconstruct the original source byte-for-byte.
+
+use ruff_text_size::{TextRange, TextSize};
+
+use crate::lexer::LexResult;
+use crate::Tok;
+
+/// A piece of trivia: source text that doesn't affect parsing but that a full-fidelity consumer
+/// (a formatter, a refactoring tool) needs in order to reconstruct the original source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Trivia {
+    /// A `#`-prefixed comment, not including the trailing newline.
+    Comment(TextRange),
+    /// A run of whitespace between tokens: spaces, tabs, a line's indentation, or a non-logical
+    /// newline.
+    Whitespace(TextRange),
+    /// A `\`-newline line continuation.
+    Continuation(TextRange),
+}
+
+impl Trivia {
+    /// The [`TextRange`] this trivia occupies in the source.
+    pub fn range(&self) -> TextRange {
+        match self {
+            Trivia::Comment(range) | Trivia::Whitespace(range) | Trivia::Continuation(range) => {
+                *range
+            }
+        }
+    }
+}
+
+/// A significant token, together with the trivia that surrounds it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenWithTrivia {
+    pub token: Tok,
+    pub range: TextRange,
+    /// Trivia that precedes this token and follows the previous significant token.
+    pub leading_trivia: Vec<Trivia>,
+    /// A same-line trailing comment, if any (e.g. `x = 1  # comment`).
+    pub trailing_comment: Option<Trivia>,
+}
+
+/// Attaches trivia -- comments, whitespace, line continuations -- to the significant token that
+/// follows it, with one exception: a comment that trails a token on the same line is attached to
+/// that preceding token instead, since moving code around should generally carry same-line
+/// comments along with it.
+///
+/// `tokens` must come from [`crate::tokenize_lossless`] (or another lexer run over `source` with
+/// errors not truncating the stream); `source` must be that same source, since the plain
+/// whitespace and line-continuation backslashes between tokens aren't tokens in their own right --
+/// they're recovered here by looking at what's in the gap between one token's end and the next
+/// token's start. Lexer errors are dropped from the result, since a caller doing full-fidelity
+/// reconstruction has already chosen to tolerate a best-effort token stream.
+pub fn attach_trivia(tokens: Vec<LexResult>, source: &str) -> Vec<TokenWithTrivia> {
+    let mut attached: Vec<TokenWithTrivia> = Vec::new();
+    let mut pending_leading: Vec<Trivia> = Vec::new();
+    let mut previous_end = TextSize::from(0);
+
+    for result in tokens {
+        let Ok((tok, range)) = result else {
+            continue;
+        };
+
+        pending_leading.extend(gap_trivia(source, previous_end, range.start()));
+        previous_end = range.end();
+
+        match &tok {
+            Tok::Comment(_) => {
+                if let Some(last) = attached.last_mut() {
+                    if !matches!(last.token, Tok::Newline)
+                        && !source_has_newline_between(source, last.range.end(), range.start())
+                    {
+                        last.trailing_comment = Some(Trivia::Comment(range));
+                        continue;
+                    }
+                }
+                pending_leading.push(Trivia::Comment(range));
+            }
+            Tok::NonLogicalNewline => {
+                pending_leading.push(Trivia::Whitespace(range));
+            }
+            _ => {
+                attached.push(TokenWithTrivia {
+                    token: tok,
+                    range,
+                    leading_trivia: std::mem::take(&mut pending_leading),
+                    trailing_comment: None,
+                });
+            }
+        }
+    }
+
+    attached
+}
+
+/// Splits the source text between `start` and `end` -- a gap the lexer didn't emit any token for
+/// -- into [`Trivia::Continuation`] pieces (a `\` immediately followed by a newline) and
+/// [`Trivia::Whitespace`] for everything else, in source order.
+fn gap_trivia(source: &str, start: TextSize, end: TextSize) -> Vec<Trivia> {
+    if start >= end {
+        return Vec::new();
+    }
+
+    let gap = &source[start.to_usize()..end.to_usize()];
+    let mut trivia = Vec::new();
+    let mut whitespace_start = start;
+    let mut offset = start;
+
+    let mut chars = gap.char_indices().peekable();
+    while let Some((i, ch)) = chars.next() {
+        if ch == '\\' {
+            if let Some(&(_, next)) = chars.peek() {
+                if next == '\n' || next == '\r' {
+                    if whitespace_start < offset {
+                        trivia.push(Trivia::Whitespace(TextRange::new(whitespace_start, offset)));
+                    }
+                    let newline_len = if next == '\r' && gap.as_bytes().get(i + 2) == Some(&b'\n')
+                    {
+                        3
+                    } else {
+                        2
+                    };
+                    let continuation_end = start + TextSize::try_from(i + newline_len).unwrap();
+                    trivia.push(Trivia::Continuation(TextRange::new(
+                        start + TextSize::try_from(i).unwrap(),
+                        continuation_end,
+                    )));
+                    whitespace_start = continuation_end;
+                }
+            }
+        }
+        offset = start + TextSize::try_from(i + ch.len_utf8()).unwrap();
+    }
+
+    if whitespace_start < end {
+        trivia.push(Trivia::Whitespace(TextRange::new(whitespace_start, end)));
+    }
+
+    trivia
+}
+
+/// Returns `true` if the source text between `start` and `end` contains a newline, i.e. whether
+/// the token ending at `start` and the token starting at `end` are on different lines.
+fn source_has_newline_between(source: &str, start: TextSize, end: TextSize) -> bool {
+    source[start.to_usize()..end.to_usize()].contains('\n')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tokenize_lossless, Mode};
+
+    #[test]
+    fn trailing_comment_attaches_to_the_preceding_token() {
+        let source = "x = 1  # comment\n";
+        let tokens = attach_trivia(tokenize_lossless(source, Mode::Module), source);
+
+        let one = tokens
+            .iter()
+            .find(|t| matches!(t.token, Tok::Int { .. }))
+            .expect("an Int token");
+        assert!(one.trailing_comment.is_some());
+    }
+
+    #[test]
+    fn line_continuation_is_captured_as_trivia() {
+        let source = "x = 1 + \\\n    2\n";
+        let tokens = attach_trivia(tokenize_lossless(source, Mode::Module), source);
+
+        let has_continuation = tokens
+            .iter()
+            .flat_map(|t| &t.leading_trivia)
+            .any(|trivia| matches!(trivia, Trivia::Continuation(_)));
+        assert!(has_continuation);
+    }
+}